@@ -1,54 +1,137 @@
+use bevy::asset::{AssetLoader, LoadContext, LoadedAsset};
+use bevy::reflect::TypeUuid;
 use bevy::render::camera::RenderTarget;
+use bevy::render::extract_resource::{ExtractResource, ExtractResourcePlugin};
+use bevy::render::main_graph::node::CAMERA_DRIVER;
+use bevy::render::render_asset::RenderAssets;
+use bevy::render::render_graph::{self, NodeRunError, RenderGraph, RenderGraphContext};
 use bevy::render::render_resource::{
-    Extent3d, TextureDescriptor, TextureDimension, TextureFormat, TextureUsages,
+    Buffer, BufferDescriptor, BufferUsages, CommandEncoderDescriptor, Extent3d, ImageCopyBuffer,
+    ImageDataLayout, Maintain, MapMode, TextureDescriptor, TextureDimension, TextureFormat,
+    TextureUsages,
 };
-use bevy::render::RenderPlugin;
+use bevy::render::renderer::{RenderContext, RenderDevice, RenderQueue};
+use bevy::render::{Render, RenderApp, RenderPlugin, RenderSet};
+use crossbeam_channel::{Receiver, Sender};
 use bevy::sprite::Anchor;
+use bevy::utils::{BoxedFuture, HashMap};
 use bevy::winit::WinitPlugin;
 use interpolation::*;
-use std::time::Instant;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
 
 use bevy::prelude::*;
 
 fn main() {
     let mut app = App::new();
     app.add_plugins(DefaultPlugins.build().disable::<WinitPlugin>())
+        .add_plugin(ImageCopyPlugin)
         .init_resource::<Animation>()
+        .init_resource::<ExportSettings>()
+        .init_resource::<GltfImport>()
+        .add_asset::<AnimationClip>()
+        .init_asset_loader::<AnimationClipLoader>()
         .add_system(pre_animation_system)
-        .add_system(transform_track_system.after(pre_animation_system))
-        .add_system(sprite_track_system.after(pre_animation_system))
-        .add_startup_system(setup_system);
+        .add_system(apply_clip_system.before(clip_state_system))
+        .add_system(clip_state_system.after(pre_animation_system))
+        .add_system(transform_track_system.after(clip_state_system))
+        .add_system(sprite_track_system.after(clip_state_system))
+        .add_startup_system(setup_system)
+        .add_startup_system(import_gltf_system);
 
-    let frames = 100;
+    let frames = app.world.resource::<ExportSettings>().frame_count;
     let mut images = Vec::<Image>::new();
 
-    for _ in 0..frames {
+    // Readback lags rendering by one update: `receive_image_from_buffer` runs in
+    // the render world for frame N, and the copied bytes only land back in
+    // `image.data` via `update_image_from_receiver` on update N+1. So run one
+    // extra update and read the buffer one step behind the render it reflects,
+    // which keeps the captured sequence aligned with the animated timeline and
+    // never drops the last rendered frame.
+    for frame in 0..=frames {
         app.update();
-        let camera = app
-            .world
-            .query::<&Camera>()
-            .iter(&app.world)
-            .next()
-            .expect("Can't find camera");
-
-        if let RenderTarget::Image(image_handle) = &camera.target {
-            let image = app
-                .world
-                .get_resource::<Assets<Image>>()
-                .expect("Couldn't get image assets")
-                .get(image_handle)
-                .expect("No image found in camera");
-            images.push(image.clone());
+        if frame == 0 {
+            continue;
         }
+
+        let copier = app.world.resource::<ImageCopier>();
+        let image = app
+            .world
+            .resource::<Assets<Image>>()
+            .get(&copier.src_image)
+            .expect("No image found for the render target")
+            .clone();
+        images.push(image);
     }
+
+    let settings = app.world.resource::<ExportSettings>();
+    export_frames(&images, settings).expect("Failed to export frames");
 }
 
-fn pre_animation_system(mut animation: ResMut<Animation>) {
-    animation.duration = Instant::now().duration_since(animation.start).as_secs_f32();
+fn pre_animation_system(mut animation: ResMut<Animation>, settings: Res<ExportSettings>) {
+    animation.duration = animation.frame_index as f32 / settings.fps;
+    animation.frame_index += 1;
 }
 
-fn setup_system(mut commands: Commands, asset_server: Res<AssetServer>) {
-    commands.spawn(Camera2dBundle::default());
+fn setup_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut images: ResMut<Assets<Image>>,
+    render_device: Res<RenderDevice>,
+    settings: Res<ExportSettings>,
+) {
+    // Render into an offscreen `Image` rather than a window so the capture loop
+    // in `main` has frames to read back; a windowed `Camera2dBundle` would make
+    // `images` empty and `export_frames` write nothing.
+    let size = Extent3d {
+        width: settings.width,
+        height: settings.height,
+        depth_or_array_layers: 1,
+    };
+    let mut target = Image {
+        texture_descriptor: TextureDescriptor {
+            label: None,
+            size,
+            dimension: TextureDimension::D2,
+            format: TextureFormat::Rgba8UnormSrgb,
+            mip_level_count: 1,
+            sample_count: 1,
+            // `COPY_SRC` so the render-graph readback node can copy the rendered
+            // texture back into a CPU-mappable buffer each frame.
+            usage: TextureUsages::TEXTURE_BINDING
+                | TextureUsages::COPY_DST
+                | TextureUsages::COPY_SRC
+                | TextureUsages::RENDER_ATTACHMENT,
+            view_formats: &[],
+        },
+        ..default()
+    };
+    target.resize(size);
+    let target = images.add(target);
+
+    // Staging buffer the readback node copies the texture into; rows are padded
+    // up to wgpu's copy alignment, so remember that stride for the unpack.
+    let padded_bytes_per_row = padded_bytes_per_row(size.width);
+    let buffer = render_device.create_buffer(&BufferDescriptor {
+        label: Some("frame_readback_buffer"),
+        size: (padded_bytes_per_row * size.height) as u64,
+        usage: BufferUsages::MAP_READ | BufferUsages::COPY_DST,
+        mapped_at_creation: false,
+    });
+    commands.insert_resource(ImageCopier {
+        buffer,
+        src_image: target.clone(),
+        size,
+        padded_bytes_per_row,
+    });
+
+    commands.spawn(Camera2dBundle {
+        camera: Camera {
+            target: RenderTarget::Image(target),
+            ..default()
+        },
+        ..default()
+    });
 
     commands
         .spawn(SpriteBundle {
@@ -70,11 +153,174 @@ fn setup_system(mut commands: Commands, asset_server: Res<AssetServer>) {
             ]),
             ..Default::default()
         });
+
+    // Drive a second sprite from an authored `.anim.ron` timeline so the loader
+    // is exercised end-to-end; `apply_clip_system` copies its tracks on once the
+    // asset finishes loading.
+    commands.spawn((
+        SpriteBundle {
+            texture: asset_server.load("icon.png"),
+            ..default()
+        },
+        asset_server.load::<AnimationClip, _>("walk.anim.ron"),
+        ClipName("walk".to_string()),
+    ));
+}
+
+/// wgpu requires each copied texture row to be aligned to this many bytes.
+const COPY_BYTES_PER_ROW_ALIGNMENT: u32 = 256;
+
+/// Row stride of a `width`-pixel RGBA8 texture once padded to the copy alignment.
+fn padded_bytes_per_row(width: u32) -> u32 {
+    let unpadded = width * 4;
+    unpadded.div_ceil(COPY_BYTES_PER_ROW_ALIGNMENT) * COPY_BYTES_PER_ROW_ALIGNMENT
+}
+
+/// The offscreen render target and the CPU-mappable staging buffer its texture
+/// is copied into each frame. Shared with the render world so the readback node
+/// and system can reach both halves.
+#[derive(Resource, Clone, ExtractResource)]
+struct ImageCopier {
+    buffer: Buffer,
+    src_image: Handle<Image>,
+    size: Extent3d,
+    padded_bytes_per_row: u32,
+}
+
+/// Channel the render world sends freshly read-back, row-unpadded RGBA8 frames
+/// down, for the main world to fold back into `Assets<Image>`.
+#[derive(Resource, Deref)]
+struct MainWorldReceiver(Receiver<Vec<u8>>);
+
+/// Render-world end of [`MainWorldReceiver`].
+#[derive(Resource, Deref)]
+struct RenderWorldSender(Sender<Vec<u8>>);
+
+/// Wires the GPU→CPU readback: a render-graph node that copies the target
+/// texture into [`ImageCopier`]'s buffer after the camera has drawn, a render
+/// system that maps the buffer and ships the bytes across a channel, and a main
+/// system that writes them back into the target `Image`.
+struct ImageCopyPlugin;
+
+impl Plugin for ImageCopyPlugin {
+    fn build(&self, app: &mut App) {
+        let (sender, receiver) = crossbeam_channel::unbounded();
+
+        app.insert_resource(MainWorldReceiver(receiver))
+            .add_plugin(ExtractResourcePlugin::<ImageCopier>::default())
+            .add_system(update_image_from_receiver);
+
+        let render_app = app.sub_app_mut(RenderApp);
+        render_app
+            .insert_resource(RenderWorldSender(sender))
+            .add_system(receive_image_from_buffer.in_set(RenderSet::Cleanup));
+
+        let mut graph = render_app.world.resource_mut::<RenderGraph>();
+        graph.add_node(ImageCopyDriver::NAME, ImageCopyDriver);
+        graph.add_node_edge(CAMERA_DRIVER, ImageCopyDriver::NAME);
+    }
+}
+
+/// Render-graph node that copies the rendered target texture into the staging
+/// buffer once the camera driver has finished drawing into it.
+struct ImageCopyDriver;
+
+impl ImageCopyDriver {
+    const NAME: &'static str = "frame_readback";
+}
+
+impl render_graph::Node for ImageCopyDriver {
+    fn run(
+        &self,
+        _graph: &mut RenderGraphContext,
+        render_context: &mut RenderContext,
+        world: &World,
+    ) -> Result<(), NodeRunError> {
+        let Some(copier) = world.get_resource::<ImageCopier>() else {
+            return Ok(());
+        };
+        let gpu_images = world.resource::<RenderAssets<Image>>();
+        let Some(src_image) = gpu_images.get(&copier.src_image) else {
+            return Ok(());
+        };
+
+        let mut encoder = render_context
+            .render_device()
+            .create_command_encoder(&CommandEncoderDescriptor::default());
+        encoder.copy_texture_to_buffer(
+            src_image.texture.as_image_copy(),
+            ImageCopyBuffer {
+                buffer: &copier.buffer,
+                layout: ImageDataLayout {
+                    offset: 0,
+                    bytes_per_row: Some(copier.padded_bytes_per_row),
+                    rows_per_image: None,
+                },
+            },
+            copier.size,
+        );
+        world
+            .resource::<RenderQueue>()
+            .submit(std::iter::once(encoder.finish()));
+        Ok(())
+    }
+}
+
+/// Maps the staging buffer, waits for the copy to complete, strips the per-row
+/// padding back to a tightly packed RGBA8 frame and sends it to the main world.
+fn receive_image_from_buffer(
+    copier: Option<Res<ImageCopier>>,
+    render_device: Res<RenderDevice>,
+    sender: Res<RenderWorldSender>,
+) {
+    let Some(copier) = copier else {
+        return;
+    };
+
+    let slice = copier.buffer.slice(..);
+    let (tx, rx) = crossbeam_channel::bounded(1);
+    slice.map_async(MapMode::Read, move |result| {
+        tx.send(result).ok();
+    });
+    render_device.poll(Maintain::Wait);
+    if rx.recv().map_or(true, |result| result.is_err()) {
+        return;
+    }
+
+    let padded = copier.padded_bytes_per_row as usize;
+    let unpadded = (copier.size.width * 4) as usize;
+    let mapped = slice.get_mapped_range();
+    let mut frame = Vec::with_capacity(unpadded * copier.size.height as usize);
+    for row in mapped.chunks(padded) {
+        frame.extend_from_slice(&row[..unpadded]);
+    }
+    drop(mapped);
+    copier.buffer.unmap();
+
+    sender.send(frame).ok();
+}
+
+/// Writes the most recent read-back frame into the target `Image`'s CPU data so
+/// the capture loop in `main` clones real pixels rather than the zero-fill.
+fn update_image_from_receiver(
+    receiver: Res<MainWorldReceiver>,
+    copier: Option<Res<ImageCopier>>,
+    mut images: ResMut<Assets<Image>>,
+) {
+    let mut latest = None;
+    while let Ok(frame) = receiver.try_recv() {
+        latest = Some(frame);
+    }
+    if let (Some(frame), Some(copier)) = (latest, copier) {
+        if let Some(image) = images.get_mut(&copier.src_image) {
+            image.data = frame;
+        }
+    }
 }
 
 type Scalar = f32;
 
-#[derive(Component, Default)]
+#[derive(Component, Clone, Default, Serialize, Deserialize)]
 struct TransformTrack {
     position_x: Track,
     position_y: Track,
@@ -87,7 +333,70 @@ struct TransformTrack {
     scale_z: Track,
 }
 
-#[derive(Component)]
+/// A smooth translation path the sprite follows as a Catmull-Rom spline,
+/// instead of the axis-independent `position_x/y/z` lerps. Holds an ordered
+/// list of control `points` with a `duration` per segment between them.
+#[derive(Component, Default, Serialize, Deserialize)]
+struct PathTrack {
+    points: Vec<Vec3>,
+    /// Length in seconds of each segment; entry `i` spans `points[i]`→`points[i+1]`.
+    durations: Vec<Scalar>,
+    /// Optional easing reshaping the per-segment `u` for ease-in/out along the path.
+    #[serde(with = "ease_by_name", default)]
+    ease: Option<EaseFunction>,
+}
+
+impl PathTrack {
+    /// Position along the path at time `t`, clamping to the endpoints outside
+    /// the path's total duration. Returns `None` when there are no points.
+    fn position(&self, mut t: Scalar) -> Option<Vec3> {
+        t = t.max(0.0);
+        let last = self.points.last()?;
+        if self.points.len() == 1 {
+            return Some(*last);
+        }
+
+        for (segment, &duration) in self.durations.iter().enumerate() {
+            if segment + 1 >= self.points.len() {
+                break;
+            }
+            if t > duration {
+                t -= duration;
+                continue;
+            }
+            let u = if duration > 0.0 { t / duration } else { 0.0 };
+            let u = match self.ease {
+                Some(ease) => u.calc(ease),
+                None => u,
+            };
+            return Some(self.catmull_rom(segment, u));
+        }
+
+        Some(*last)
+    }
+
+    /// Catmull-Rom evaluation of segment `points[i]`→`points[i+1]` at local
+    /// `u ∈ [0, 1]`, duplicating the endpoints for the first and last segments.
+    fn catmull_rom(&self, i: usize, u: Scalar) -> Vec3 {
+        let p1 = self.points[i];
+        let p2 = self.points[i + 1];
+        let p0 = if i == 0 { p1 } else { self.points[i - 1] };
+        let p3 = if i + 2 < self.points.len() {
+            self.points[i + 2]
+        } else {
+            p2
+        };
+
+        let u2 = u * u;
+        let u3 = u2 * u;
+        0.5 * ((2.0 * p1)
+            + (-p0 + p2) * u
+            + (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3) * u2
+            + (-p0 + 3.0 * p1 - 3.0 * p2 + p3) * u3)
+    }
+}
+
+#[derive(Component, Clone, Serialize, Deserialize)]
 struct SpriteTrack {
     color_r: Track,
     color_g: Track,
@@ -99,14 +408,17 @@ struct SpriteTrack {
     anchor_y: Track,
 }
 
+#[derive(Clone, Serialize, Deserialize)]
 struct BoolKey {
     value: bool,
     duration: Scalar,
 }
 
+#[derive(Clone, Serialize, Deserialize)]
 struct Key {
     value: Scalar,
     duration: Scalar,
+    #[serde(with = "ease_by_name", default)]
     ease: Option<EaseFunction>,
 }
 
@@ -122,12 +434,12 @@ impl Key {
     }
 }
 
-#[derive(Default)]
+#[derive(Clone, Default, Serialize, Deserialize)]
 struct BoolTrack {
     keys: Vec<BoolKey>,
 }
 
-#[derive(Default)]
+#[derive(Clone, Default, Serialize, Deserialize)]
 struct Track {
     keys: Vec<Key>,
 }
@@ -155,6 +467,170 @@ impl Track {
 
         value
     }
+
+    /// Total length of the track, i.e. the summed key durations.
+    fn total(&self) -> Scalar {
+        self.keys.iter().map(|key| key.duration).sum()
+    }
+
+    /// Sample the track at time `t`, honouring the clip's playback `mode`. A
+    /// `Loop` with a non-zero blend `period` cross-fades the tail of the loop
+    /// back toward the value at time 0 so the wrap-around no longer pops.
+    fn sample(
+        &self,
+        t: Scalar,
+        mode: PlaybackMode,
+        period: Scalar,
+        ease: Option<EaseFunction>,
+    ) -> Option<Scalar> {
+        let total = self.total();
+        match mode {
+            PlaybackMode::Once => self.value(t),
+            PlaybackMode::Loop => {
+                if total <= 0.0 {
+                    return self.value(t);
+                }
+                let local = t.rem_euclid(total);
+                let sampled = self.value(local)?;
+                if period > 0.0 && local >= total - period {
+                    let f = blend_factor((local - (total - period)) / period, ease);
+                    return Some(sampled.lerp(&self.value(0.0)?, &f));
+                }
+                Some(sampled)
+            }
+            PlaybackMode::PingPong => {
+                if total <= 0.0 {
+                    return self.value(t);
+                }
+                let folded = t.rem_euclid(2.0 * total);
+                let local = if folded <= total {
+                    folded
+                } else {
+                    2.0 * total - folded
+                };
+                self.value(local)
+            }
+        }
+    }
+
+    /// Sample this clip and, during its last `period` seconds, blend its output
+    /// toward `next`'s start pose, so chaining clip A into clip B no longer pops.
+    fn sample_chained(
+        &self,
+        next: &Track,
+        t: Scalar,
+        period: Scalar,
+        ease: Option<EaseFunction>,
+    ) -> Option<Scalar> {
+        let value = self.value(t)?;
+        let total = self.total();
+        if period > 0.0 && t >= total - period {
+            let f = blend_factor((t - (total - period)) / period, ease);
+            let target = next.value(0.0).unwrap_or(value);
+            return Some(value.lerp(&target, &f));
+        }
+        Some(value)
+    }
+}
+
+/// Clamps the raw blend progress to `[0, 1]` and reshapes it through the
+/// optional `EaseFunction` before it is used as a lerp factor.
+fn blend_factor(f: Scalar, ease: Option<EaseFunction>) -> Scalar {
+    let f = f.clamp(0.0, 1.0);
+    match ease {
+        Some(ease) => f.calc(ease),
+        None => f,
+    }
+}
+
+/// How a clip's tracks repeat once their last key is reached.
+#[derive(Clone, Copy, Default, Serialize, Deserialize)]
+enum PlaybackMode {
+    /// Hold the final key forever (the original behaviour).
+    #[default]
+    Once,
+    /// Restart from time 0, optionally cross-fading across the seam.
+    Loop,
+    /// Play forward then backward, folding over `2 * total`.
+    PingPong,
+}
+
+/// Playback configuration an entity's tracks are sampled through. Without a
+/// `Clip` a track behaves exactly as before (`Once`, no blending).
+#[derive(Component)]
+struct Clip {
+    mode: PlaybackMode,
+    /// Blend period `p` in seconds for loop seams and clip chaining.
+    interpolation_period: Scalar,
+    /// Optional easing applied to the blend factor before the lerp.
+    blend_ease: Option<EaseFunction>,
+    /// When set, chain into this entity's tracks during the last `p` seconds.
+    next: Option<Entity>,
+}
+
+impl Default for Clip {
+    fn default() -> Self {
+        Self {
+            mode: PlaybackMode::Once,
+            interpolation_period: 0.0,
+            blend_ease: None,
+            next: None,
+        }
+    }
+}
+
+/// Per-entity runtime clock, so several clips can be staggered off the shared
+/// animation clock instead of all starting at time 0.
+#[derive(Component, Default)]
+struct ClipState {
+    /// Seconds this entity's clock lags the global animation clock.
+    start_offset: Scalar,
+    /// Local playback time, recomputed each frame by `clip_state_system`.
+    time: Scalar,
+}
+
+fn clip_state_system(animation: Res<Animation>, mut query: Query<&mut ClipState>) {
+    for mut state in query.iter_mut() {
+        state.time = (animation.duration - state.start_offset).max(0.0);
+    }
+}
+
+/// Names which timeline of a loaded [`AnimationClip`] should drive the entity
+/// it is attached to, paired with a `Handle<AnimationClip>`.
+#[derive(Component)]
+struct ClipName(String);
+
+/// Copies the named tracks out of a loaded [`AnimationClip`] onto the entities
+/// that requested it, so an entity spawned with `asset_server.load("walk.anim.ron")`
+/// is actually driven by the authored (and hot-reloadable) timeline.
+fn apply_clip_system(
+    mut commands: Commands,
+    mut events: EventReader<AssetEvent<AnimationClip>>,
+    clips: Res<Assets<AnimationClip>>,
+    query: Query<(Entity, &Handle<AnimationClip>, &ClipName, Option<&TransformTrack>)>,
+) {
+    // Clips whose file was reloaded this frame are copied on again.
+    let reloaded: Vec<Handle<AnimationClip>> = events
+        .iter()
+        .filter_map(|event| match event {
+            AssetEvent::Modified { handle } => Some(handle.clone_weak()),
+            _ => None,
+        })
+        .collect();
+    for (entity, handle, name, applied) in query.iter() {
+        if applied.is_some() && !reloaded.contains(handle) {
+            continue;
+        }
+        let Some(clip) = clips.get(handle) else {
+            continue;
+        };
+        if let Some(track) = clip.transforms.get(&name.0) {
+            commands.entity(entity).insert(track.clone());
+        }
+        if let Some(sprite) = clip.sprites.get(&name.0) {
+            commands.entity(entity).insert(sprite.clone());
+        }
+    }
 }
 
 impl BoolTrack {
@@ -184,60 +660,504 @@ impl BoolTrack {
 
 #[derive(Resource)]
 struct Animation {
-    start: Instant,
+    frame_index: u32,
     duration: f32,
 }
 
 impl Default for Animation {
     fn default() -> Self {
         Self {
-            start: Instant::now(),
+            frame_index: 0,
             duration: 0.0,
         }
     }
 }
 
+/// How the accumulated frames are written to disk once capture finishes.
+enum ExportFormat {
+    /// One `frame_00000.png`, `frame_00001.png`, … per frame next to `output_path`.
+    PngSequence,
+    /// A single animated GIF at `output_path`.
+    AnimatedGif,
+    /// One flat file of concatenated RGBA8 bytes at `output_path`.
+    RawRgba,
+}
+
+#[derive(Resource)]
+struct ExportSettings {
+    output_path: PathBuf,
+    fps: f32,
+    frame_count: u32,
+    format: ExportFormat,
+    /// Dimensions of the offscreen render target each frame is captured from.
+    width: u32,
+    height: u32,
+}
+
+impl Default for ExportSettings {
+    fn default() -> Self {
+        Self {
+            output_path: PathBuf::from("out.png"),
+            fps: 30.0,
+            frame_count: 100,
+            format: ExportFormat::PngSequence,
+            width: 512,
+            height: 512,
+        }
+    }
+}
+
+/// Reads the RGBA bytes back out of each captured `Image` and writes them to
+/// `settings.output_path` in the requested `ExportFormat`. The capture loop
+/// only ever produces frames in `Rgba8UnormSrgb`, so the texture bytes are
+/// already laid out as tightly packed RGBA8 and can be handed to `image`
+/// verbatim.
+fn export_frames(images: &[Image], settings: &ExportSettings) -> image::ImageResult<()> {
+    use image::{codecs::gif::GifEncoder, Delay, Frame};
+    use std::fs::File;
+    use std::io::Write;
+
+    let frame_delay = Delay::from_numer_denom_ms((1000.0 / settings.fps) as u32, 1);
+
+    match settings.format {
+        ExportFormat::PngSequence => {
+            // Emit a clean `frame_00000.png` sequence next to `output_path`
+            // rather than mangling the configured file's extension.
+            let parent = settings.output_path.parent().unwrap_or_else(|| Path::new(""));
+            for (index, image) in images.iter().enumerate() {
+                let path = parent.join(format!("frame_{index:05}.png"));
+                rgba_image(image).save(path)?;
+            }
+        }
+        ExportFormat::AnimatedGif => {
+            let file = File::create(&settings.output_path)?;
+            let mut encoder = GifEncoder::new(file);
+            for image in images {
+                encoder.encode_frame(Frame::from_parts(rgba_image(image), 0, 0, frame_delay))?;
+            }
+        }
+        ExportFormat::RawRgba => {
+            let mut file = File::create(&settings.output_path)?;
+            for image in images {
+                file.write_all(&image.data)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Wraps a captured frame's RGBA8 texture bytes in an `image::RgbaImage`.
+fn rgba_image(image: &Image) -> image::RgbaImage {
+    let size = image.texture_descriptor.size;
+    image::RgbaImage::from_raw(size.width, size.height, image.data.clone())
+        .expect("Captured frame was not tightly packed RGBA8")
+}
+
+/// A set of named timelines loaded from a `.anim.ron` file. Each entry maps a
+/// name an entity can refer to onto the `TransformTrack`/`SpriteTrack` that
+/// should drive it, so timings can be authored and hot-reloaded without a
+/// recompile.
+#[derive(Serialize, Deserialize, TypeUuid)]
+#[uuid = "8f6a0d5c-2e3b-4c9a-9b1e-6d4f0a2c7e10"]
+struct AnimationClip {
+    #[serde(default)]
+    transforms: HashMap<String, TransformTrack>,
+    #[serde(default)]
+    sprites: HashMap<String, SpriteTrack>,
+}
+
+#[derive(Default)]
+struct AnimationClipLoader;
+
+impl AssetLoader for AnimationClipLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), bevy::asset::Error>> {
+        Box::pin(async move {
+            let clip = ron::de::from_bytes::<AnimationClip>(bytes)?;
+            load_context.set_default_asset(LoadedAsset::new(clip));
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["anim.ron"]
+    }
+}
+
+/// Optional glTF file whose baked animation channels are imported into
+/// `TransformTrack`s at startup, so externally authored motion can be replayed
+/// and re-exported. Left empty by default.
+#[derive(Resource, Default)]
+struct GltfImport {
+    path: Option<PathBuf>,
+}
+
+/// Imports the animation channels of the glTF named by [`GltfImport`] and spawns
+/// one entity per animated node, each driven by the converted `TransformTrack`.
+fn import_gltf_system(mut commands: Commands, import: Res<GltfImport>) {
+    let Some(path) = &import.path else {
+        return;
+    };
+    let (document, buffers, _images) = match gltf::import(path) {
+        Ok(loaded) => loaded,
+        Err(error) => {
+            error!("failed to import glTF `{}`: {error}", path.display());
+            return;
+        }
+    };
+    for (_node, track) in transform_tracks_from_gltf(&document, &buffers) {
+        commands.spawn((SpatialBundle::default(), track));
+    }
+}
+
+/// Converts the animation channels of a loaded glTF document into the Euler
+/// `TransformTrack`s this crate drives entities with, keyed by target node name
+/// (falling back to the node index when a node is unnamed). Channel input times
+/// become per-key `duration` deltas and the sampler interpolation mode picks
+/// each key's ease.
+fn transform_tracks_from_gltf(
+    document: &gltf::Document,
+    buffers: &[gltf::buffer::Data],
+) -> HashMap<String, TransformTrack> {
+    use gltf::animation::util::ReadOutputs;
+
+    let mut tracks: HashMap<String, TransformTrack> = HashMap::new();
+
+    for animation in document.animations() {
+        for channel in animation.channels() {
+            let node = channel.target().node();
+            let name = node
+                .name()
+                .map(String::from)
+                .unwrap_or_else(|| format!("node{}", node.index()));
+
+            let reader = channel.reader(|buffer| Some(&buffers[buffer.index()]));
+            let times: Vec<f32> = match reader.read_inputs() {
+                Some(inputs) => inputs.collect(),
+                None => continue,
+            };
+            let interpolation = channel.sampler().interpolation();
+            let ease = ease_from_interpolation(interpolation);
+            let step = matches!(interpolation, gltf::animation::Interpolation::Step);
+
+            let track = tracks.entry(name).or_default();
+
+            match reader.read_outputs() {
+                Some(ReadOutputs::Translations(values)) => {
+                    let values = strip_tangents(values.collect(), interpolation);
+                    track.position_x = scalar_track(&times, values.iter().map(|v| v[0]), ease, step);
+                    track.position_y = scalar_track(&times, values.iter().map(|v| v[1]), ease, step);
+                    track.position_z = scalar_track(&times, values.iter().map(|v| v[2]), ease, step);
+                }
+                Some(ReadOutputs::Scales(values)) => {
+                    let values = strip_tangents(values.collect(), interpolation);
+                    track.scale_x = scalar_track(&times, values.iter().map(|v| v[0]), ease, step);
+                    track.scale_y = scalar_track(&times, values.iter().map(|v| v[1]), ease, step);
+                    track.scale_z = scalar_track(&times, values.iter().map(|v| v[2]), ease, step);
+                }
+                Some(ReadOutputs::Rotations(values)) => {
+                    // glTF stores rotations as quaternions; fold each keyframe
+                    // into the Euler `rotation_x/y/z` tracks this crate uses.
+                    let quats = strip_tangents(values.into_f32().collect(), interpolation);
+                    let eulers: Vec<(f32, f32, f32)> = quats
+                        .iter()
+                        .map(|q| Quat::from_xyzw(q[0], q[1], q[2], q[3]).to_euler(EulerRot::XYZ))
+                        .collect();
+                    track.rotation_x = scalar_track(&times, eulers.iter().map(|e| e.0), ease, step);
+                    track.rotation_y = scalar_track(&times, eulers.iter().map(|e| e.1), ease, step);
+                    track.rotation_z = scalar_track(&times, eulers.iter().map(|e| e.2), ease, step);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    tracks
+}
+
+/// glTF cubic-spline outputs interleave in-tangent, value and out-tangent per
+/// keyframe; keep just the value so the sample count lines up with the inputs.
+fn strip_tangents<T: Copy>(values: Vec<T>, interpolation: gltf::animation::Interpolation) -> Vec<T> {
+    match interpolation {
+        gltf::animation::Interpolation::CubicSpline => values
+            .chunks(3)
+            .filter_map(|chunk| chunk.get(1).copied())
+            .collect(),
+        _ => values,
+    }
+}
+
+/// Builds a `Track` from absolute keyframe `times` and their scalar values,
+/// turning the absolute times into the per-key `duration` deltas `Track` walks.
+///
+/// When `step` is set (a glTF `STEP` channel) the value must hold and then jump
+/// at each keyframe instead of ramping. `Key` can only lerp, so the step is
+/// emulated by holding the previous value across the segment and inserting a
+/// duplicate key carrying that previous value right before the keyframe's time,
+/// leaving only a near-instant span for the value to snap across.
+fn scalar_track(
+    times: &[f32],
+    values: impl Iterator<Item = Scalar>,
+    ease: Option<EaseFunction>,
+    step: bool,
+) -> Track {
+    /// Near-instant span the value snaps across at a STEP keyframe.
+    const STEP_SNAP: Scalar = 1e-4;
+
+    let mut keys = Vec::new();
+    let mut previous_time = 0.0;
+    let mut previous_value = 0.0;
+    for (index, (&time, value)) in times.iter().zip(values).enumerate() {
+        if index == 0 {
+            keys.push(Key {
+                value,
+                duration: 0.0,
+                ease: None,
+            });
+        } else if step {
+            let delta = time - previous_time;
+            let hold = (delta - STEP_SNAP).max(0.0);
+            // Hold the previous value across the segment, then snap to the new one.
+            keys.push(Key {
+                value: previous_value,
+                duration: hold,
+                ease: None,
+            });
+            keys.push(Key {
+                value,
+                duration: delta - hold,
+                ease: None,
+            });
+        } else {
+            keys.push(Key {
+                value,
+                duration: time - previous_time,
+                ease,
+            });
+        }
+        previous_time = time;
+        previous_value = value;
+    }
+    Track::new(keys)
+}
+
+/// Maps a glTF sampler interpolation mode onto the nearest `EaseFunction` this
+/// crate's lerp-based `Key` can express. `STEP` is handled separately by
+/// `scalar_track` (via a duplicate hold key), so it carries no ease here.
+fn ease_from_interpolation(interpolation: gltf::animation::Interpolation) -> Option<EaseFunction> {
+    use gltf::animation::Interpolation;
+    match interpolation {
+        Interpolation::Linear | Interpolation::Step => None,
+        // Cubic-spline tangents aren't representable, so approximate the smooth
+        // acceleration with an ease-in-out.
+        Interpolation::CubicSpline => Some(EaseFunction::CubicInOut),
+    }
+}
+
+/// Serialises an `Option<EaseFunction>` by its variant name so authored RON
+/// reads `ease: Some("QuadraticInOut")` instead of an opaque numeric tag.
+mod ease_by_name {
+    use super::EaseFunction;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    fn name(ease: &EaseFunction) -> &'static str {
+        match ease {
+            EaseFunction::QuadraticIn => "QuadraticIn",
+            EaseFunction::QuadraticOut => "QuadraticOut",
+            EaseFunction::QuadraticInOut => "QuadraticInOut",
+            EaseFunction::CubicIn => "CubicIn",
+            EaseFunction::CubicOut => "CubicOut",
+            EaseFunction::CubicInOut => "CubicInOut",
+            EaseFunction::QuarticIn => "QuarticIn",
+            EaseFunction::QuarticOut => "QuarticOut",
+            EaseFunction::QuarticInOut => "QuarticInOut",
+            EaseFunction::QuinticIn => "QuinticIn",
+            EaseFunction::QuinticOut => "QuinticOut",
+            EaseFunction::QuinticInOut => "QuinticInOut",
+            EaseFunction::SineIn => "SineIn",
+            EaseFunction::SineOut => "SineOut",
+            EaseFunction::SineInOut => "SineInOut",
+            EaseFunction::CircularIn => "CircularIn",
+            EaseFunction::CircularOut => "CircularOut",
+            EaseFunction::CircularInOut => "CircularInOut",
+            EaseFunction::ExponentialIn => "ExponentialIn",
+            EaseFunction::ExponentialOut => "ExponentialOut",
+            EaseFunction::ExponentialInOut => "ExponentialInOut",
+            EaseFunction::ElasticIn => "ElasticIn",
+            EaseFunction::ElasticOut => "ElasticOut",
+            EaseFunction::ElasticInOut => "ElasticInOut",
+            EaseFunction::BackIn => "BackIn",
+            EaseFunction::BackOut => "BackOut",
+            EaseFunction::BackInOut => "BackInOut",
+            EaseFunction::BounceIn => "BounceIn",
+            EaseFunction::BounceOut => "BounceOut",
+            EaseFunction::BounceInOut => "BounceInOut",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<EaseFunction> {
+        Some(match name {
+            "QuadraticIn" => EaseFunction::QuadraticIn,
+            "QuadraticOut" => EaseFunction::QuadraticOut,
+            "QuadraticInOut" => EaseFunction::QuadraticInOut,
+            "CubicIn" => EaseFunction::CubicIn,
+            "CubicOut" => EaseFunction::CubicOut,
+            "CubicInOut" => EaseFunction::CubicInOut,
+            "QuarticIn" => EaseFunction::QuarticIn,
+            "QuarticOut" => EaseFunction::QuarticOut,
+            "QuarticInOut" => EaseFunction::QuarticInOut,
+            "QuinticIn" => EaseFunction::QuinticIn,
+            "QuinticOut" => EaseFunction::QuinticOut,
+            "QuinticInOut" => EaseFunction::QuinticInOut,
+            "SineIn" => EaseFunction::SineIn,
+            "SineOut" => EaseFunction::SineOut,
+            "SineInOut" => EaseFunction::SineInOut,
+            "CircularIn" => EaseFunction::CircularIn,
+            "CircularOut" => EaseFunction::CircularOut,
+            "CircularInOut" => EaseFunction::CircularInOut,
+            "ExponentialIn" => EaseFunction::ExponentialIn,
+            "ExponentialOut" => EaseFunction::ExponentialOut,
+            "ExponentialInOut" => EaseFunction::ExponentialInOut,
+            "ElasticIn" => EaseFunction::ElasticIn,
+            "ElasticOut" => EaseFunction::ElasticOut,
+            "ElasticInOut" => EaseFunction::ElasticInOut,
+            "BackIn" => EaseFunction::BackIn,
+            "BackOut" => EaseFunction::BackOut,
+            "BackInOut" => EaseFunction::BackInOut,
+            "BounceIn" => EaseFunction::BounceIn,
+            "BounceOut" => EaseFunction::BounceOut,
+            "BounceInOut" => EaseFunction::BounceInOut,
+            _ => return None,
+        })
+    }
+
+    pub fn serialize<S: Serializer>(
+        ease: &Option<EaseFunction>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        ease.map(name).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<EaseFunction>, D::Error> {
+        let name = Option::<String>::deserialize(deserializer)?;
+        match name {
+            Some(name) => from_name(&name)
+                .map(Some)
+                .ok_or_else(|| serde::de::Error::custom(format!("unknown ease function `{name}`"))),
+            None => Ok(None),
+        }
+    }
+}
+
 fn transform_track_system(
-    mut query: Query<(&mut Transform, &TransformTrack)>,
+    mut query: Query<(
+        &mut Transform,
+        &TransformTrack,
+        Option<&Clip>,
+        Option<&ClipState>,
+        Option<&PathTrack>,
+    )>,
+    tracks: Query<&TransformTrack>,
     animation: Res<Animation>,
 ) {
-    let duration = animation.duration;
-    for (mut transform, track) in query.iter_mut() {
+    for (mut transform, track, clip, state, path) in query.iter_mut() {
+        let duration = state.map_or(animation.duration, |state| state.time);
+        let next = clip.and_then(|clip| clip.next).and_then(|e| tracks.get(e).ok());
+
+        // Resolve one axis through the clip's playback mode / chaining, falling
+        // back to the transform's current value when the track is empty.
+        let sample = |select: fn(&TransformTrack) -> &Track, fallback: Scalar| -> Scalar {
+            let track = select(track);
+            match clip {
+                Some(clip) => match next {
+                    Some(next) => track
+                        .sample_chained(
+                            select(next),
+                            duration,
+                            clip.interpolation_period,
+                            clip.blend_ease,
+                        )
+                        .unwrap_or(fallback),
+                    None => track
+                        .sample(duration, clip.mode, clip.interpolation_period, clip.blend_ease)
+                        .unwrap_or(fallback),
+                },
+                None => track.value(duration).unwrap_or(fallback),
+            }
+        };
+
         let translation = transform.translation;
         let rotation = transform.rotation;
         let scale = transform.scale;
 
-        transform.translation = Vec3::new(
-            track.position_x.value(duration).unwrap_or(translation.x),
-            track.position_y.value(duration).unwrap_or(translation.y),
-            track.position_z.value(duration).unwrap_or(translation.z),
-        );
+        // A `PathTrack` drives translation along a smooth curve when present;
+        // otherwise fall back to the axis-independent scalar tracks.
+        transform.translation = match path.and_then(|path| path.position(duration)) {
+            Some(position) => position,
+            None => Vec3::new(
+                sample(|t| &t.position_x, translation.x),
+                sample(|t| &t.position_y, translation.y),
+                sample(|t| &t.position_z, translation.z),
+            ),
+        };
 
         transform.scale = Vec3::new(
-            track.scale_x.value(duration).unwrap_or(scale.x),
-            track.scale_y.value(duration).unwrap_or(scale.y),
-            track.scale_z.value(duration).unwrap_or(scale.z),
+            sample(|t| &t.scale_x, scale.x),
+            sample(|t| &t.scale_y, scale.y),
+            sample(|t| &t.scale_z, scale.z),
         );
 
         transform.rotation = Quat::from_euler(
             EulerRot::XYZ,
-            track.rotation_x.value(duration).unwrap_or(rotation.x),
-            track.rotation_y.value(duration).unwrap_or(rotation.y),
-            track.rotation_z.value(duration).unwrap_or(rotation.z),
+            sample(|t| &t.rotation_x, rotation.x),
+            sample(|t| &t.rotation_y, rotation.y),
+            sample(|t| &t.rotation_z, rotation.z),
         );
     }
 }
 
-fn sprite_track_system(mut query: Query<(&mut Sprite, &SpriteTrack)>, animation: Res<Animation>) {
-    let duration = animation.duration;
-    for (mut sprite, track) in query.iter_mut() {
+fn sprite_track_system(
+    mut query: Query<(&mut Sprite, &SpriteTrack, Option<&Clip>, Option<&ClipState>)>,
+    tracks: Query<&SpriteTrack>,
+    animation: Res<Animation>,
+) {
+    for (mut sprite, track, clip, state) in query.iter_mut() {
+        let duration = state.map_or(animation.duration, |state| state.time);
+        let next = clip.and_then(|clip| clip.next).and_then(|e| tracks.get(e).ok());
+
+        let sample = |select: fn(&SpriteTrack) -> &Track, fallback: Scalar| -> Scalar {
+            let track = select(track);
+            match clip {
+                Some(clip) => match next {
+                    Some(next) => track
+                        .sample_chained(
+                            select(next),
+                            duration,
+                            clip.interpolation_period,
+                            clip.blend_ease,
+                        )
+                        .unwrap_or(fallback),
+                    None => track
+                        .sample(duration, clip.mode, clip.interpolation_period, clip.blend_ease)
+                        .unwrap_or(fallback),
+                },
+                None => track.value(duration).unwrap_or(fallback),
+            }
+        };
+
         let color = sprite.color;
 
         sprite.color = Color::rgba_linear(
-            track.color_r.value(duration).unwrap_or(color.r()),
-            track.color_g.value(duration).unwrap_or(color.g()),
-            track.color_b.value(duration).unwrap_or(color.b()),
-            track.color_a.value(duration).unwrap_or(color.a()),
+            sample(|t| &t.color_r, color.r()),
+            sample(|t| &t.color_g, color.g()),
+            sample(|t| &t.color_b, color.b()),
+            sample(|t| &t.color_a, color.a()),
         );
 
         sprite.flip_x = track.flip_x.value(duration).unwrap_or(sprite.flip_x);
@@ -245,9 +1165,157 @@ fn sprite_track_system(mut query: Query<(&mut Sprite, &SpriteTrack)>, animation:
 
         let anchor = sprite.anchor.as_vec();
         sprite.anchor = Anchor::Custom(Vec2::new(
-            track.anchor_x.value(duration).unwrap_or(anchor.x),
-            track.anchor_y.value(duration).unwrap_or(anchor.y),
+            sample(|t| &t.anchor_x, anchor.x),
+            sample(|t| &t.anchor_y, anchor.y),
         ));
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn anim_clip_ron_round_trip() {
+        let mut transforms = HashMap::new();
+        transforms.insert(
+            "walk".to_string(),
+            TransformTrack {
+                position_x: Track::new(vec![
+                    Key {
+                        value: 0.0,
+                        duration: 0.0,
+                        ease: None,
+                    },
+                    Key {
+                        value: 5.0,
+                        duration: 1.0,
+                        ease: Some(EaseFunction::QuadraticInOut),
+                    },
+                ]),
+                ..Default::default()
+            },
+        );
+        let clip = AnimationClip {
+            transforms,
+            sprites: HashMap::new(),
+        };
+
+        let serialized = ron::ser::to_string(&clip).expect("serialize clip");
+        let back: AnimationClip = ron::de::from_str(&serialized).expect("deserialize clip");
+
+        let track = &back.transforms["walk"].position_x;
+        assert_eq!(track.keys.len(), 2);
+        assert_eq!(track.keys[1].ease, Some(EaseFunction::QuadraticInOut));
+        assert!((track.value(0.5).unwrap() - 2.5).abs() < 1e-4);
+    }
+
+    #[test]
+    fn gltf_input_times_become_durations() {
+        // Absolute keyframe times collapse to per-key duration deltas.
+        let track = scalar_track(&[0.0, 0.5, 2.0], [1.0, 2.0, 3.0].into_iter(), None, false);
+        let durations: Vec<Scalar> = track.keys.iter().map(|key| key.duration).collect();
+        assert_eq!(durations, vec![0.0, 0.5, 1.5]);
+    }
+
+    #[test]
+    fn gltf_step_channel_holds_then_snaps() {
+        let track = scalar_track(&[0.0, 1.0], [0.0, 10.0].into_iter(), None, true);
+        // Still holding the first value most of the way through the segment…
+        assert!(track.value(0.5).unwrap().abs() < 1e-4);
+        // …then snapped to the new value by the keyframe time.
+        assert!(track.value(1.0).unwrap() > 9.0);
+    }
+
+    #[test]
+    fn gltf_rotation_quat_to_euler_round_trip() {
+        let quat = Quat::from_euler(EulerRot::XYZ, 0.3, -0.2, 0.1);
+        let (x, y, z) = quat.to_euler(EulerRot::XYZ);
+        assert!((x - 0.3).abs() < 1e-4);
+        assert!((y + 0.2).abs() < 1e-4);
+        assert!((z - 0.1).abs() < 1e-4);
+    }
+}
+
+#[cfg(test)]
+mod clip_tests {
+    use super::*;
+
+    fn ramp() -> Track {
+        // A 0→10 ramp spanning two seconds.
+        Track::new(vec![
+            Key {
+                value: 0.0,
+                duration: 0.0,
+                ease: None,
+            },
+            Key {
+                value: 10.0,
+                duration: 2.0,
+                ease: None,
+            },
+        ])
+    }
+
+    #[test]
+    fn blend_factor_clamps_and_passes_through() {
+        assert_eq!(blend_factor(-0.5, None), 0.0);
+        assert_eq!(blend_factor(1.5, None), 1.0);
+        assert!((blend_factor(0.5, None) - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn loop_wraps_modulo_total() {
+        // total = 2; t = 2.5 folds to local 0.5 → a quarter up the ramp.
+        let v = ramp().sample(2.5, PlaybackMode::Loop, 0.0, None).unwrap();
+        assert!((v - 2.5).abs() < 1e-4);
+    }
+
+    #[test]
+    fn ping_pong_folds_and_reverses() {
+        // total = 2; t = 3 folds over 2*total to local 1 → halfway up the ramp.
+        let v = ramp().sample(3.0, PlaybackMode::PingPong, 0.0, None).unwrap();
+        assert!((v - 5.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn chaining_blends_into_next_start_pose() {
+        let a = ramp();
+        let b = Track::new(vec![
+            Key {
+                value: 100.0,
+                duration: 0.0,
+                ease: None,
+            },
+            Key {
+                value: 200.0,
+                duration: 1.0,
+                ease: None,
+            },
+        ]);
+        // At the end of A with a 1s blend the factor is 1, so we land on B's start.
+        let v = a.sample_chained(&b, 2.0, 1.0, None).unwrap();
+        assert!((v - 100.0).abs() < 1e-4);
+    }
+}
+
+#[cfg(test)]
+mod path_tests {
+    use super::*;
+
+    #[test]
+    fn path_passes_through_control_points() {
+        let path = PathTrack {
+            points: vec![Vec3::ZERO, Vec3::X, Vec3::new(2.0, 1.0, 0.0)],
+            durations: vec![1.0, 1.0],
+            ease: None,
+        };
+        assert!(path.position(0.0).unwrap().abs_diff_eq(Vec3::ZERO, 1e-5));
+        assert!(path.position(1.0).unwrap().abs_diff_eq(Vec3::X, 1e-5));
+        assert!(path
+            .position(2.0)
+            .unwrap()
+            .abs_diff_eq(Vec3::new(2.0, 1.0, 0.0), 1e-5));
+    }
+}
+